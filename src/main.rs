@@ -5,22 +5,45 @@ use std::{
     env,
     error::Error,
     fmt::{Display, Formatter},
+    sync::{Arc, OnceLock},
 };
 
 use serenity::{
-    async_trait, http::Http, model::event::ResumedEvent, model::gateway::Ready, prelude::*,
+    async_trait,
+    builder::{
+        CreateCommand, CreateCommandOption, CreateInteractionResponse,
+        CreateInteractionResponseFollowup, CreateInteractionResponseMessage,
+    },
+    http::Http,
+    model::application::{Command, CommandOptionType, CommandType, Interaction},
+    model::event::ResumedEvent,
+    model::gateway::Ready,
+    model::id::{ChannelId, GuildId, UserId},
+    model::permissions::Permissions,
+    prelude::*,
 };
 
+use tokio::sync::RwLock;
 use tracing::{error, info, warn};
 use url::Url;
 
 //use crate::commands::meta::*;
 //use crate::commands::owner::*;
 
-#[derive(serde::Deserialize, Debug)]
+mod store;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 struct DdBotChannelConfig {
     pub default_lang: String,
     pub target_lang: String,
+    // DeepL's `source_lang`, `formality` (`more`/`less`/`prefer_more`/`prefer_less`) and
+    // `glossary_id` options, forwarded to DeepL verbatim when set.
+    #[serde(default)]
+    pub source_lang: Option<String>,
+    #[serde(default)]
+    pub formality: Option<String>,
+    #[serde(default)]
+    pub glossary_id: Option<String>,
 }
 
 mod deepl {
@@ -28,6 +51,12 @@ mod deepl {
     pub(crate) struct DeepLTranslationRequestBody {
         pub text: Vec<String>,
         pub target_lang: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub source_lang: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub formality: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub glossary_id: Option<String>,
     }
 
     #[derive(serde::Deserialize, Debug)]
@@ -40,6 +69,17 @@ mod deepl {
         pub text: String,
         pub detected_source_language: String,
     }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub(crate) struct DeepLUsageResponse {
+        pub character_count: u64,
+        pub character_limit: u64,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub(crate) struct DeepLLanguage {
+        pub language: String,
+    }
 }
 
 impl Display for deepl::DeeplTranslationResopnse {
@@ -56,8 +96,19 @@ impl Display for deepl::DeeplTranslationResopnse {
 
 type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
 
+// DeepL's supported source/target language codes, fetched once at startup and consulted by
+// `/config-set` so a bad language code is rejected as a configuration error instead of
+// failing every translation request.
+static SUPPORTED_TARGET_LANGUAGES: OnceLock<HashSet<String>> = OnceLock::new();
+static SUPPORTED_SOURCE_LANGUAGES: OnceLock<HashSet<String>> = OnceLock::new();
+
 // Event handler for the bot
-struct Handler;
+struct Handler {
+    // Application owners, used to gate owner-only commands like `/usage`.
+    owners: HashSet<UserId>,
+    // Per-guild/per-channel language configuration, set via `/config-set`.
+    store: Arc<RwLock<store::ConfigStore>>,
+}
 
 #[async_trait]
 impl EventHandler for Handler {
@@ -79,13 +130,25 @@ impl EventHandler for Handler {
             return;
         }
 
-        let config = get_language_config(&ctx, &msg).await;
+        let store = self.store.read().await;
+        let config = get_language_config(msg.guild_id, msg.channel_id, &store).await;
+        drop(store);
         let deepl_api_key = &env::var("DEEPL_API_KEY").unwrap(); // Safe unwrap - the value is checked at startup
 
-        match deepl_translate(&msg.content, &config.target_lang, deepl_api_key) {
+        match deepl_translate(
+            &msg.content,
+            &config.target_lang,
+            deepl_api_key,
+            config.source_lang.as_deref(),
+            config.formality.as_deref(),
+            config.glossary_id.as_deref(),
+        )
+        .await
+        {
             Ok(translation_result) => {
                 if let Some(reply_message) =
                     create_reply_message(&translation_result, &config, &msg.content, deepl_api_key)
+                        .await
                 {
                     if let Err(reason) = msg.reply(&ctx.http, reply_message).await {
                         error!("Failed to reply translation result: {:?}", reason);
@@ -109,6 +172,213 @@ impl EventHandler for Handler {
             }
         }
     }
+
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        let deepl_api_key = &env::var("DEEPL_API_KEY").unwrap(); // Safe unwrap - the value is checked at startup
+
+        let Interaction::Command(command) = interaction else {
+            return;
+        };
+
+        if command.data.kind == CommandType::ChatInput && command.data.name == "usage" {
+            // Defer immediately: the DeepL usage call below can take longer than Discord's
+            // 3-second ack window, so reply with a followup instead of the initial response.
+            defer_ephemeral(&ctx, &command).await;
+
+            let content = if !self.owners.contains(&command.user.id) {
+                "This command is restricted to the application owner.".to_string()
+            } else {
+                match deepl_usage(deepl_api_key).await {
+                    Ok(usage) => format!(
+                        "DeepL usage: {} / {} characters ({:.1}%)",
+                        usage.character_count,
+                        usage.character_limit,
+                        100.0 * usage.character_count as f64 / usage.character_limit as f64
+                    ),
+                    Err(e) => {
+                        error!("Error fetching DeepL usage: {:?}", e);
+                        "Failed to fetch DeepL usage".to_string()
+                    }
+                }
+            };
+
+            follow_up_ephemeral(&ctx, &command, content).await;
+            return;
+        }
+
+        if command.data.kind == CommandType::ChatInput && command.data.name == "config-set" {
+            let Some(guild_id) = command.guild_id else {
+                respond_ephemeral(&ctx, &command, "This command can only be used in a server.").await;
+                return;
+            };
+
+            let option = |name: &str| -> Option<String> {
+                command
+                    .data
+                    .options
+                    .iter()
+                    .find(|opt| opt.name == name)
+                    .and_then(|opt| opt.value.as_str())
+                    .map(str::to_string)
+            };
+
+            let (Some(target_lang), Some(default_lang)) = (option("target_lang"), option("default_lang"))
+            else {
+                respond_ephemeral(&ctx, &command, "`target_lang` and `default_lang` are required.").await;
+                return;
+            };
+
+            let config = DdBotChannelConfig {
+                target_lang,
+                default_lang,
+                source_lang: option("source_lang"),
+                formality: option("formality"),
+                glossary_id: option("glossary_id"),
+            };
+
+            if let Some(invalid_lang) = first_unsupported_language(&config) {
+                respond_ephemeral(&ctx, &command, unsupported_language_message(&invalid_lang)).await;
+                return;
+            }
+
+            self.store
+                .write()
+                .await
+                .set(guild_id, command.channel_id, config)
+                .await;
+            respond_ephemeral(&ctx, &command, "Configuration saved for this channel.").await;
+            return;
+        }
+
+        if command.data.kind == CommandType::ChatInput && command.data.name == "config-get" {
+            let Some(guild_id) = command.guild_id else {
+                respond_ephemeral(&ctx, &command, "This command can only be used in a server.").await;
+                return;
+            };
+
+            let content = match self.store.read().await.get(guild_id, command.channel_id) {
+                Some(config) => format!("{config:?}"),
+                None => "No configuration set for this channel; using environment defaults.".to_string(),
+            };
+            respond_ephemeral(&ctx, &command, content).await;
+            return;
+        }
+
+        let is_translate_command = (command.data.kind == CommandType::ChatInput
+            && command.data.name == "translate")
+            || (command.data.kind == CommandType::Message && command.data.name == "Translate");
+        if !is_translate_command {
+            return;
+        }
+        // Defer immediately: the DeepL translation call below can take longer than Discord's
+        // 3-second ack window, so reply with a followup instead of the initial response.
+        defer_ephemeral(&ctx, &command).await;
+
+        let (text, target_lang, source_lang, formality, glossary_id) = match command.data.kind {
+            CommandType::ChatInput if command.data.name == "translate" => {
+                let text = command
+                    .data
+                    .options
+                    .iter()
+                    .find(|opt| opt.name == "text")
+                    .and_then(|opt| opt.value.as_str())
+                    .unwrap_or_default()
+                    .to_string();
+                let target_lang = command
+                    .data
+                    .options
+                    .iter()
+                    .find(|opt| opt.name == "to")
+                    .and_then(|opt| opt.value.as_str())
+                    .unwrap_or("EN")
+                    .to_string();
+                (text, target_lang, None, None, None)
+            }
+            CommandType::Message if command.data.name == "Translate" => {
+                let Some(message) = command.data.target_id.and_then(|id| {
+                    command.data.resolved.messages.get(&id.to_message_id())
+                }) else {
+                    warn!("Message context menu command fired without a resolved target message");
+                    follow_up_ephemeral(&ctx, &command, "Failed to read the target message.").await;
+                    return;
+                };
+                let store = self.store.read().await;
+                let config =
+                    get_language_config(command.guild_id, command.channel_id, &store).await;
+                drop(store);
+                (
+                    message.content.clone(),
+                    config.target_lang,
+                    config.source_lang,
+                    config.formality,
+                    config.glossary_id,
+                )
+            }
+            _ => return,
+        };
+
+        let content = match translate_reply_text(
+            &text,
+            &target_lang,
+            deepl_api_key,
+            source_lang.as_deref(),
+            formality.as_deref(),
+            glossary_id.as_deref(),
+        )
+        .await
+        {
+            Ok(reply) => reply,
+            Err(e) => {
+                error!("Error translating via interaction: {:?}", e);
+                "Failed to translate using DeepL".to_string()
+            }
+        };
+
+        follow_up_ephemeral(&ctx, &command, content).await;
+    }
+}
+
+// Send an ephemeral reply to a command interaction - shared by every `interaction_create` branch.
+async fn respond_ephemeral(
+    ctx: &Context,
+    command: &serenity::model::application::CommandInteraction,
+    content: impl Into<String>,
+) {
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(content)
+            .ephemeral(true),
+    );
+    if let Err(reason) = command.create_response(&ctx.http, response).await {
+        error!("Failed to respond to interaction: {:?}", reason);
+    }
+}
+
+// Acknowledge a command interaction without a final reply yet, showing Discord's "thinking..."
+// state. Used by branches that make a DeepL call that can outlast Discord's 3-second ack window;
+// the final content is delivered with `follow_up_ephemeral` once the call completes.
+async fn defer_ephemeral(ctx: &Context, command: &serenity::model::application::CommandInteraction) {
+    let response = CreateInteractionResponse::Defer(
+        CreateInteractionResponseMessage::new().ephemeral(true),
+    );
+    if let Err(reason) = command.create_response(&ctx.http, response).await {
+        error!("Failed to defer interaction: {:?}", reason);
+    }
+}
+
+// Deliver the final reply to a command interaction previously acknowledged with
+// `defer_ephemeral`.
+async fn follow_up_ephemeral(
+    ctx: &Context,
+    command: &serenity::model::application::CommandInteraction,
+    content: impl Into<String>,
+) {
+    let followup = CreateInteractionResponseFollowup::new()
+        .content(content)
+        .ephemeral(true);
+    if let Err(reason) = command.create_followup(&ctx.http, followup).await {
+        error!("Failed to send interaction followup: {:?}", reason);
+    }
 }
 
 // Create a reply message using the translation result from DeepL API
@@ -120,7 +390,7 @@ impl EventHandler for Handler {
 // Also, if the detected source language is unknown language to the channel (e.g, `NL`),
 // then translate the original text to both the target language and the default language.
 //
-fn create_reply_message(
+async fn create_reply_message(
     deepl_response: &deepl::DeeplTranslationResopnse,
     language_config: &DdBotChannelConfig,
     original_text: &str,
@@ -145,7 +415,15 @@ fn create_reply_message(
         // e.g, `NL`: detected source language, `JA`: target language, `EN`: default language
         // add default language translation, too
         if let Ok(translation_result) =
-            deepl_translate(original_text, &language_config.default_lang, deepl_api_key)
+            deepl_translate(
+                original_text,
+                &language_config.default_lang,
+                deepl_api_key,
+                language_config.source_lang.as_deref(),
+                language_config.formality.as_deref(),
+                language_config.glossary_id.as_deref(),
+            )
+            .await
         {
             Some(format!(
                 "`{}: ` {} \n`{}: ` {} \n(translated from `{}`)",
@@ -166,7 +444,15 @@ fn create_reply_message(
         // if the detected source language is the same as the target language,
         // return reverse translation (e.g, `JA(target_language)` => EN(default language)`)
         if let Ok(translation_result) =
-            deepl_translate(original_text, &language_config.default_lang, deepl_api_key)
+            deepl_translate(
+                original_text,
+                &language_config.default_lang,
+                deepl_api_key,
+                language_config.source_lang.as_deref(),
+                language_config.formality.as_deref(),
+                language_config.glossary_id.as_deref(),
+            )
+            .await
         {
             Some(format!(
                 "`{}: ` {}",
@@ -189,68 +475,234 @@ fn create_reply_message(
     }
 }
 
-// Get language configuration from the channel topic
+// Get language configuration for a message's channel, set via `/config-set` and kept in the
+// persistent store, falling back to environment defaults when nothing has been configured.
 async fn get_language_config(
-    ctx: &Context,
-    msg: &serenity::model::channel::Message,
+    guild_id: Option<GuildId>,
+    channel_id: ChannelId,
+    store: &store::ConfigStore,
 ) -> DdBotChannelConfig {
-    // Default language configuration from environment variables
-    let default_lang = &env::var("DEFAULT_LANGUAGE").unwrap_or(String::from("JA"));
-    let target_lang = &env::var("TARGET_LANGUAGE").unwrap_or(String::from("JA"));
     let default_config = DdBotChannelConfig {
-        default_lang: default_lang.clone(),
-        target_lang: target_lang.clone(),
+        default_lang: env::var("DEFAULT_LANGUAGE").unwrap_or(String::from("JA")),
+        target_lang: env::var("TARGET_LANGUAGE").unwrap_or(String::from("JA")),
+        source_lang: None,
+        formality: None,
+        glossary_id: None,
     };
 
-    let Ok(channel) = msg.channel(&ctx.http).await else {
-        error!("Failed to get channel for message - use default {default_lang}");
-        return default_config;
-    };
-    let Some(guild_channel) = channel.guild() else {
-        error!("Failed to get channel for message - use default {default_lang}");
+    let Some(guild_id) = guild_id else {
         return default_config;
     };
 
-    let Some(topic) = guild_channel.topic else {
-        error!("Failed to get channel for message - use default {target_lang}");
-        return default_config;
-    };
+    store
+        .get(guild_id, channel_id)
+        .cloned()
+        .unwrap_or(default_config)
+}
+
+// Returns the first configured language code that DeepL's supported-languages caches say they
+// don't know about, if the relevant cache has been populated. A cache stays empty (and its
+// checks are skipped) if its startup fetch in `main` failed, so config is never rejected only
+// because DeepL's language list couldn't be fetched.
+fn first_unsupported_language(config: &DdBotChannelConfig) -> Option<String> {
+    if let Some(valid) = SUPPORTED_TARGET_LANGUAGES.get() {
+        if !valid.contains(&config.target_lang.to_uppercase()) {
+            return Some(config.target_lang.clone());
+        }
+        // `default_lang` is passed as the `target_lang` argument to `deepl_translate` in
+        // every reverse-translation call, so it must satisfy the same (stricter) target
+        // language list as `target_lang`, not the source list.
+        if !valid.contains(&config.default_lang.to_uppercase()) {
+            return Some(config.default_lang.clone());
+        }
+    }
+    if let (Some(source_lang), Some(valid)) =
+        (config.source_lang.as_ref(), SUPPORTED_SOURCE_LANGUAGES.get())
+    {
+        if !valid.contains(&source_lang.to_uppercase()) {
+            return Some(source_lang.clone());
+        }
+    }
+    None
+}
 
-    let config: DdBotChannelConfig = serde_json::from_str(&topic).unwrap_or(default_config);
-    config
+// Error message for `/config-set` when it's given an unsupported language code, listing the
+// known-valid target language codes.
+fn unsupported_language_message(invalid_lang: &str) -> String {
+    let mut valid_codes: Vec<&str> = SUPPORTED_TARGET_LANGUAGES
+        .get()
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect();
+    valid_codes.sort_unstable();
+
+    format!(
+        "Unsupported language code `{invalid_lang}`. Valid codes: {}",
+        valid_codes.join(", ")
+    )
+}
+
+// DeepL Free accounts are keyed by an api key suffixed with `:fx` and must use the
+// `api-free` host; Pro accounts use `api`. Centralize that choice here so every DeepL
+// call picks the right endpoint regardless of which plan the configured key belongs to.
+fn deepl_base_url(api_key: &str) -> &'static str {
+    if api_key.ends_with(":fx") {
+        "https://api-free.deepl.com"
+    } else {
+        "https://api.deepl.com"
+    }
+}
+
+// Shared `reqwest::Client` for every DeepL call, so concurrent requests reuse pooled
+// connections instead of paying a fresh TCP+TLS handshake per call.
+static DEEPL_HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+fn deepl_http_client() -> &'static reqwest::Client {
+    DEEPL_HTTP_CLIENT.get_or_init(reqwest::Client::new)
 }
 
 // Send a translation request to the DeepL API
-fn deepl_translate(
+async fn deepl_translate(
     text: &str,
     target_lang: &str,
     api_key: &str,
+    source_lang: Option<&str>,
+    formality: Option<&str>,
+    glossary_id: Option<&str>,
 ) -> Result<deepl::DeeplTranslationResopnse> {
-    match ureq::post("https://api.deepl.com/v2/translate")
+    let client = deepl_http_client();
+    let response = client
+        .post(format!("{}/v2/translate", deepl_base_url(api_key)))
         .header("Authorization", format!("DeepL-Auth-Key {api_key}"))
         .header("Content-Type", "application/json")
-        .send_json(deepl::DeepLTranslationRequestBody {
+        .json(&deepl::DeepLTranslationRequestBody {
             text: vec![text.to_string()],
             target_lang: target_lang.to_string(),
-        }) {
-        Ok(mut response) => {
-            let translated_texts = response
-                .body_mut()
-                .read_json::<deepl::DeeplTranslationResopnse>()?;
+            source_lang: source_lang.map(String::from),
+            formality: formality.map(String::from),
+            glossary_id: glossary_id.map(String::from),
+        })
+        .send()
+        .await
+        .map_err(|_| "Failed to connect to DeepL API")?;
+
+    if !response.status().is_success() {
+        return Err(format!("Server error: {}", response.status()).into());
+    }
+
+    let translated_texts = response
+        .json::<deepl::DeeplTranslationResopnse>()
+        .await?;
+
+    Ok(translated_texts)
+}
+
+// Query the DeepL account's current character usage against its monthly quota.
+async fn deepl_usage(api_key: &str) -> Result<deepl::DeepLUsageResponse> {
+    let client = deepl_http_client();
+    let response = client
+        .get(format!("{}/v2/usage", deepl_base_url(api_key)))
+        .header("Authorization", format!("DeepL-Auth-Key {api_key}"))
+        .send()
+        .await
+        .map_err(|_| "Failed to connect to DeepL API")?;
+
+    if !response.status().is_success() {
+        return Err(format!("Server error: {}", response.status()).into());
+    }
+
+    let usage = response.json::<deepl::DeepLUsageResponse>().await?;
+    Ok(usage)
+}
+
+// Fetch DeepL's list of currently supported languages for `lang_type` (`"source"` or `"target"`).
+async fn deepl_languages(api_key: &str, lang_type: &str) -> Result<Vec<deepl::DeepLLanguage>> {
+    let client = deepl_http_client();
+    let response = client
+        .get(format!("{}/v2/languages", deepl_base_url(api_key)))
+        .header("Authorization", format!("DeepL-Auth-Key {api_key}"))
+        .query(&[("type", lang_type)])
+        .send()
+        .await
+        .map_err(|_| "Failed to connect to DeepL API")?;
 
-            Ok(translated_texts)
+    if !response.status().is_success() {
+        return Err(format!("Server error: {}", response.status()).into());
+    }
+
+    let languages = response.json::<Vec<deepl::DeepLLanguage>>().await?;
+    Ok(languages)
+}
+
+// Fraction of the monthly character quota at which we start warning about usage, by default.
+// Overridable via the `USAGE_WARNING_THRESHOLD` environment variable.
+const DEFAULT_USAGE_WARNING_THRESHOLD: f64 = 0.9;
+
+// Background task, polled periodically from `main`, that checks DeepL usage against
+// `warning_threshold` and notifies the application owners by DM when it's exceeded.
+async fn poll_deepl_usage(http: &Http, owners: &HashSet<UserId>, api_key: &str, warning_threshold: f64) {
+    match deepl_usage(api_key).await {
+        Ok(usage) => {
+            let ratio = usage.character_count as f64 / usage.character_limit as f64;
+            if ratio >= warning_threshold {
+                warn!(
+                    "DeepL usage at {:.1}% of quota ({}/{})",
+                    ratio * 100.0,
+                    usage.character_count,
+                    usage.character_limit
+                );
+                for owner in owners {
+                    if let Err(reason) = owner
+                        .direct_message(
+                            http,
+                            serenity::builder::CreateMessage::new().content(format!(
+                                "DeepL usage warning: {:.1}% of quota used ({}/{} characters)",
+                                ratio * 100.0,
+                                usage.character_count,
+                                usage.character_limit
+                            )),
+                        )
+                        .await
+                    {
+                        error!("Failed to DM owner about DeepL usage: {:?}", reason);
+                    }
+                }
+            }
         }
-        Err(ureq::Error::StatusCode(code)) => Err(format!("Server error: {code}").into()),
-        Err(_) => Err("Failed to connect to DeepL API".into()),
+        Err(e) => error!("Failed to poll DeepL usage: {:?}", e),
     }
 }
 
+// Shared translation path for the `/translate` command and the "Translate" message
+// context-menu command: translate arbitrary text to an explicit target language and
+// format it the same way a DeepL response is normally displayed.
+async fn translate_reply_text(
+    text: &str,
+    target_lang: &str,
+    deepl_api_key: &str,
+    source_lang: Option<&str>,
+    formality: Option<&str>,
+    glossary_id: Option<&str>,
+) -> Result<String> {
+    let translation_result = deepl_translate(
+        text,
+        target_lang,
+        deepl_api_key,
+        source_lang,
+        formality,
+        glossary_id,
+    )
+    .await?;
+    Ok(translation_result.to_string())
+}
+
 #[tokio::main]
 async fn main() {
     // Read `.env` for Discord token and DeepL API key.
     dotenv::dotenv().expect("Failed to load .env file");
     // check for required environment variables
-    let _deepl_auth_key =
+    let deepl_auth_key =
         &env::var("DEEPL_API_KEY").expect("Expected an api key in the environment");
     let token = &env::var("DISCORD_TOKEN").expect("Expected a token in the environment");
 
@@ -259,13 +711,9 @@ async fn main() {
     // In this case, a good default is setting the environment variable `RUST_LOG` to `debug`.
     tracing_subscriber::fmt::init();
 
-    // TODO: In order to be able to use commands, we need to register them once.
-    // https://discord.com/developers/docs/interactions/application-commands#registering-a-command
-    // For now, commands are not necessary for this bot.
-
     // Fetch application info
     let http = Http::new(token);
-    let (_owners, _bot_id) = match http.get_current_application_info().await {
+    let (owners, _bot_id) = match http.get_current_application_info().await {
         Ok(info) => {
             let mut owners = HashSet::new();
             if let Some(owner) = &info.owner {
@@ -277,14 +725,116 @@ async fn main() {
         Err(why) => panic!("Could not access application info: {:?}", why),
     };
 
+    // Cache DeepL's supported language codes so `/config-set` can reject unsupported codes
+    // up front instead of every subsequent translation failing against the DeepL API.
+    match deepl_languages(deepl_auth_key, "target").await {
+        Ok(languages) => {
+            let _ = SUPPORTED_TARGET_LANGUAGES.set(
+                languages.into_iter().map(|l| l.language.to_uppercase()).collect(),
+            );
+        }
+        Err(why) => error!("Failed to fetch DeepL target languages: {:?}", why),
+    }
+    match deepl_languages(deepl_auth_key, "source").await {
+        Ok(languages) => {
+            let _ = SUPPORTED_SOURCE_LANGUAGES.set(
+                languages.into_iter().map(|l| l.language.to_uppercase()).collect(),
+            );
+        }
+        Err(why) => error!("Failed to fetch DeepL source languages: {:?}", why),
+    }
+
+    // Register the global application commands (chat command + context menu command).
+    // https://discord.com/developers/docs/interactions/application-commands#registering-a-command
+    let commands = vec![
+        CreateCommand::new("translate")
+            .description("Translate text with DeepL")
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::String, "text", "Text to translate")
+                    .required(true),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "to",
+                    "Target language code (e.g. EN, JA)",
+                )
+                .required(true),
+            ),
+        CreateCommand::new("Translate").kind(CommandType::Message),
+        CreateCommand::new("usage").description("Check DeepL character usage (owner only)"),
+        CreateCommand::new("config-set")
+            .description("Set this channel's translation configuration (manage server only)")
+            .default_member_permissions(Permissions::MANAGE_GUILD)
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "target_lang",
+                    "Language to translate messages into (e.g. JA)",
+                )
+                .required(true),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "default_lang",
+                    "Channel's default/home language (e.g. EN)",
+                )
+                .required(true),
+            )
+            .add_option(CreateCommandOption::new(
+                CommandOptionType::String,
+                "source_lang",
+                "Force a specific source language instead of auto-detecting",
+            ))
+            .add_option(CreateCommandOption::new(
+                CommandOptionType::String,
+                "formality",
+                "DeepL formality: more, less, prefer_more or prefer_less",
+            ))
+            .add_option(CreateCommandOption::new(
+                CommandOptionType::String,
+                "glossary_id",
+                "DeepL glossary ID to apply to translations",
+            )),
+        CreateCommand::new("config-get")
+            .description("Show this channel's translation configuration (manage server only)")
+            .default_member_permissions(Permissions::MANAGE_GUILD),
+    ];
+    if let Err(why) = Command::set_global_commands(&http, commands).await {
+        error!("Failed to register application commands: {:?}", why);
+    }
+
+    let config_store = Arc::new(RwLock::new(store::ConfigStore::load(
+        env::var("BOT_DATA_PATH").unwrap_or_else(|_| store::DEFAULT_STORE_PATH.to_string()),
+    )));
+
     let intents = GatewayIntents::GUILD_MESSAGES
         | GatewayIntents::DIRECT_MESSAGES
         | GatewayIntents::MESSAGE_CONTENT;
     let mut client = Client::builder(token, intents)
-        .event_handler(Handler)
+        .event_handler(Handler {
+            owners: owners.clone(),
+            store: config_store,
+        })
         .await
         .expect("Err creating client");
 
+    // Periodically check DeepL usage against the quota and warn the owners before it's exhausted.
+    let usage_http = client.http.clone();
+    let usage_api_key = deepl_auth_key.clone();
+    let usage_warning_threshold = env::var("USAGE_WARNING_THRESHOLD")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_USAGE_WARNING_THRESHOLD);
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(60 * 60));
+        loop {
+            interval.tick().await;
+            poll_deepl_usage(&usage_http, &owners, &usage_api_key, usage_warning_threshold).await;
+        }
+    });
+
     let shard_manager = client.shard_manager.clone();
     tokio::spawn(async move {
         tokio::signal::ctrl_c()
@@ -0,0 +1,69 @@
+//! Persistent per-guild/per-channel configuration store.
+//!
+//! Replaces parsing `DdBotChannelConfig` out of the channel topic: topics are fragile (visible
+//! to all members, length-limited), so the `/config-set` command now writes configuration here,
+//! keyed by guild and channel ID, and `get_language_config` reads it back.
+
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use serenity::model::id::{ChannelId, GuildId};
+use tracing::error;
+
+use crate::DdBotChannelConfig;
+
+pub(crate) const DEFAULT_STORE_PATH: &str = "bot-data.json";
+
+type GuildConfigs = HashMap<ChannelId, DdBotChannelConfig>;
+
+#[derive(Debug)]
+pub(crate) struct ConfigStore {
+    path: PathBuf,
+    guilds: HashMap<GuildId, GuildConfigs>,
+}
+
+impl ConfigStore {
+    // Load the store from `path`, falling back to an empty store if the file doesn't exist
+    // yet or can't be parsed.
+    pub(crate) fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let guilds = fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+
+        Self { path, guilds }
+    }
+
+    pub(crate) fn get(&self, guild_id: GuildId, channel_id: ChannelId) -> Option<&DdBotChannelConfig> {
+        self.guilds.get(&guild_id)?.get(&channel_id)
+    }
+
+    pub(crate) async fn set(
+        &mut self,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+        config: DdBotChannelConfig,
+    ) {
+        self.guilds.entry(guild_id).or_default().insert(channel_id, config);
+        self.save().await;
+    }
+
+    // Writes the store to disk on a blocking-pool thread so the gateway loop isn't stalled by
+    // the write syscall.
+    async fn save(&self) {
+        let path = self.path.clone();
+        let contents = match serde_json::to_string_pretty(&self.guilds) {
+            Ok(contents) => contents,
+            Err(reason) => {
+                error!("Failed to serialize config store: {:?}", reason);
+                return;
+            }
+        };
+
+        match tokio::task::spawn_blocking(move || fs::write(&path, contents)).await {
+            Ok(Ok(())) => {}
+            Ok(Err(reason)) => error!("Failed to save config store to {:?}: {:?}", self.path, reason),
+            Err(reason) => error!("Config store save task panicked: {:?}", reason),
+        }
+    }
+}